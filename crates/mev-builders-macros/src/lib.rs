@@ -30,6 +30,14 @@ struct BuilderJson {
     extra_data: Option<String>,
     signing: String,
     account_required: bool,
+    /// Chain the endpoints belong to. Defaults to Ethereum mainnet (1) when omitted.
+    #[serde(default = "default_chain_id")]
+    chain_id: u64,
+}
+
+/// Default chain id (Ethereum mainnet) for builder records that omit `chain_id`.
+fn default_chain_id() -> u64 {
+    1
 }
 
 #[proc_macro]
@@ -90,6 +98,7 @@ pub fn include_builders(input: TokenStream) -> TokenStream {
             };
 
             let account_required = builder.account_required;
+            let chain_id = builder.chain_id;
 
             quote! {
                 crate::Builder {
@@ -101,6 +110,7 @@ pub fn include_builders(input: TokenStream) -> TokenStream {
                     extra_data: #extra_data_tokens,
                     signing: #signing_tokens,
                     account_required: #account_required,
+                    chain_id: #chain_id,
                     blocks: #blocks,
                 }
             }
@@ -0,0 +1,133 @@
+//! Concurrent bundle broadcasting to multiple builders.
+//!
+//! [`BundleBroadcaster`] takes a ready JSON-RPC bundle body (`eth_sendBundle` /
+//! `mev_sendBundle`) and a set of target builders, signs the body per-builder
+//! according to each builder's [`Signing`](crate::Signing) policy, and fans out
+//! concurrent POSTs to every [`searcher_rpc`](crate::Builder::searcher_rpc),
+//! returning a per-builder [`BroadcastResult`].
+//!
+//! Builders that [`requires_extra_handling`](crate::Builder::requires_extra_handling)
+//! (`buildernet`'s custom cert, `bloxroute`'s account requirement) are skipped
+//! and flagged rather than blindly hit.
+
+use crate::signing::BundleSigner;
+use crate::Builder;
+use futures::future::join_all;
+use std::time::{Duration, Instant};
+
+/// Broadcasts signed bundles to builders concurrently.
+pub struct BundleBroadcaster {
+    client: reqwest::Client,
+    signer: BundleSigner,
+}
+
+/// Outcome of a single builder submission.
+#[derive(Debug)]
+pub enum BroadcastOutcome {
+    /// The builder accepted the request (HTTP 2xx with a JSON body that has no `error` field).
+    /// Carries the returned bundle hash if present.
+    Success { status: u16, bundle_hash: Option<String> },
+    /// The request failed: the body could not be signed or sent (`status` is `None`), or the
+    /// builder answered with a non-2xx status, a JSON-RPC error or a body that is not valid JSON.
+    Failed { status: Option<u16>, error: String },
+    /// The builder was not contacted because it requires special handling.
+    Skipped { reason: String },
+}
+
+/// Per-builder result of a [`BundleBroadcaster::broadcast`] call.
+#[derive(Debug)]
+pub struct BroadcastResult {
+    /// Identifier of the targeted builder.
+    pub identifier: String,
+    /// What happened for this builder.
+    pub outcome: BroadcastOutcome,
+    /// Wall-clock time spent on this builder.
+    pub latency: Duration,
+}
+
+impl BundleBroadcaster {
+    /// Create a broadcaster that signs bundles with `signer`.
+    pub fn new(signer: BundleSigner) -> Self {
+        Self { client: reqwest::Client::new(), signer }
+    }
+
+    /// Create a broadcaster with a pre-configured HTTP client.
+    pub fn with_client(client: reqwest::Client, signer: BundleSigner) -> Self {
+        Self { client, signer }
+    }
+
+    /// Broadcast `body` to every builder in `targets` concurrently.
+    pub async fn broadcast(&self, body: &str, targets: &[&Builder<'_>]) -> Vec<BroadcastResult> {
+        join_all(targets.iter().map(|builder| self.submit(body, builder))).await
+    }
+
+    /// Broadcast `body` to the builders in `targets` that satisfy `predicate`.
+    ///
+    /// Useful for selecting, for example, only builders with at least N landed
+    /// blocks, or only those supporting MEV-Share via `mev_share_rpc`.
+    pub async fn broadcast_where<'b>(
+        &self,
+        body: &str,
+        targets: &[&'b Builder<'b>],
+        predicate: impl Fn(&Builder<'b>) -> bool,
+    ) -> Vec<BroadcastResult> {
+        let selected: Vec<&Builder<'b>> = targets.iter().copied().filter(|b| predicate(b)).collect();
+        self.broadcast(body, &selected).await
+    }
+
+    async fn submit(&self, body: &str, builder: &Builder<'_>) -> BroadcastResult {
+        let started = Instant::now();
+
+        if builder.requires_extra_handling() {
+            return BroadcastResult {
+                identifier: builder.identifier.to_string(),
+                outcome: BroadcastOutcome::Skipped {
+                    reason: format!("{} requires special handling (custom cert or account)", builder.identifier),
+                },
+                latency: started.elapsed(),
+            };
+        }
+
+        let outcome = self.post(body, builder).await;
+        BroadcastResult { identifier: builder.identifier.to_string(), outcome, latency: started.elapsed() }
+    }
+
+    async fn post(&self, body: &str, builder: &Builder<'_>) -> BroadcastOutcome {
+        let mut request = self.client.post(builder.searcher_rpc).header("Content-Type", "application/json").body(body.to_string());
+
+        // Sign when the builder wants a signature (required or optional).
+        if let Some(signed) = builder.sign_payload(&self.signer, body) {
+            match signed {
+                Ok(header) => request = request.header("X-Flashbots-Signature", header),
+                Err(err) => return BroadcastOutcome::Failed { status: None, error: format!("failed to sign bundle: {err}") },
+            }
+        }
+
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(err) => return BroadcastOutcome::Failed { status: None, error: err.to_string() },
+        };
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        if !status.is_success() {
+            return BroadcastOutcome::Failed { status: Some(status.as_u16()), error: text };
+        }
+
+        match serde_json::from_str::<serde_json::Value>(&text) {
+            Ok(value) => {
+                if let Some(error) = value.get("error") {
+                    return BroadcastOutcome::Failed { status: Some(status.as_u16()), error: error.to_string() };
+                }
+                let bundle_hash = value
+                    .get("result")
+                    .and_then(|result| result.get("bundleHash").or_else(|| result.get("bundle_hash")))
+                    .and_then(|hash| hash.as_str())
+                    .map(str::to_string);
+                BroadcastOutcome::Success { status: status.as_u16(), bundle_hash }
+            }
+            Err(err) => BroadcastOutcome::Failed { status: Some(status.as_u16()), error: format!("invalid JSON response: {err}") },
+        }
+    }
+}
@@ -1,4 +1,11 @@
 use mev_builders_macros::include_builders;
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "broadcast")]
+pub mod broadcast;
+pub mod query;
+pub mod registry;
+pub mod signing;
 
 #[cfg(feature = "tools")]
 pub mod tools;
@@ -6,11 +13,16 @@ pub mod tools;
 /// List of known builders with their details, ordered by landed blocks.
 pub static BUILDERS: &[Builder] = include_builders!("data/builders.json", "data/builders_stats.json");
 
+/// Return all builders that run on the given chain, ordered by landed blocks.
+pub fn builders_for_chain(chain_id: u64) -> Vec<&'static Builder<'static>> {
+    query::BuilderQuery::from_static().chain(chain_id).collect()
+}
+
 /// Indicates if a builder requires signing for bundles using `X-Flashbots-Signature`.
 ///
 /// All builder besides Flashbots have signing as optional or not supported.
 /// If provided, the builder may give better priority to signed bundles.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 pub enum Signing {
     /// Bundle gets rejected if not signed.
     Required,
@@ -21,6 +33,21 @@ pub enum Signing {
 }
 
 impl Signing {
+    /// Map a `builders.json` signing string to a [`Signing`].
+    ///
+    /// Mirrors the lenient mapping used by the `include_builders!` macro:
+    /// anything that is not `"Required"` or `"Optional"` is treated as
+    /// [`Signing::NotSupported`], so the runtime [`Registry`](registry::Registry)
+    /// accepts the exact same `builders.json` that the compile-time
+    /// [`BUILDERS`] static does.
+    pub fn from_json_str(value: &str) -> Self {
+        match value {
+            "Required" => Signing::Required,
+            "Optional" => Signing::Optional,
+            _ => Signing::NotSupported,
+        }
+    }
+
     /// Returns true if the builder requires signing for bundles.
     pub const fn is_required(&self) -> bool {
         matches!(self, Signing::Required)
@@ -35,6 +62,16 @@ impl Signing {
     }
 }
 
+impl<'de> Deserialize<'de> for Signing {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+        Ok(Signing::from_json_str(&value))
+    }
+}
+
 /// Represents a builder with its details.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Builder<'a> {
@@ -54,6 +91,8 @@ pub struct Builder<'a> {
     pub signing: Signing,
     /// Requires account to use the RPC.
     pub account_required: bool,
+    /// Chain the endpoints belong to, as an EVM chain id (Ethereum mainnet is `1`).
+    pub chain_id: u64,
     /// Number of blocks landed by this builder.
     pub blocks: u64,
 }
@@ -0,0 +1,140 @@
+//! Fluent selector over the builder set.
+//!
+//! [`BuilderQuery`] wraps [`BUILDERS`](crate::BUILDERS) (or any other builder
+//! slice) and collects chainable predicates that are applied in a single pass,
+//! so searchers can express selections like "all no-account builders that
+//! support MEV-Share, with at least 100 landed blocks" in one line instead of
+//! hand-rolling a `HashMap` and a stack of filters.
+
+use crate::{Builder, Signing};
+use std::collections::HashMap;
+
+type Predicate<'a> = Box<dyn Fn(&Builder<'a>) -> bool + 'a>;
+
+/// A builder-pattern query over a slice of [`Builder`]s.
+///
+/// Predicates are accumulated and evaluated lazily by the terminal methods
+/// ([`collect`](Self::collect), [`first`](Self::first), [`into_map`](Self::into_map)).
+/// The source order is preserved, so when the source is [`BUILDERS`](crate::BUILDERS)
+/// results come back sorted by landed blocks.
+pub struct BuilderQuery<'a> {
+    builders: &'a [Builder<'a>],
+    predicates: Vec<Predicate<'a>>,
+}
+
+impl<'a> BuilderQuery<'a> {
+    /// Start a query over an arbitrary builder slice.
+    pub fn new(builders: &'a [Builder<'a>]) -> Self {
+        Self { builders, predicates: Vec::new() }
+    }
+
+    /// Start a query over the compile-time [`BUILDERS`](crate::BUILDERS) static.
+    pub fn from_static() -> BuilderQuery<'static> {
+        BuilderQuery::new(crate::BUILDERS)
+    }
+
+    /// Keep only builders that expose a MEV-Share endpoint.
+    pub fn with_mev_share(self) -> Self {
+        self.filter(|b| b.mev_share_rpc.is_some())
+    }
+
+    /// Keep only builders with the given signing policy.
+    pub fn signing(self, signing: Signing) -> Self {
+        self.filter(move |b| b.signing == signing)
+    }
+
+    /// Keep only builders that do not require an account.
+    pub fn account_not_required(self) -> Self {
+        self.filter(|b| !b.account_required)
+    }
+
+    /// Keep only builders with at least `blocks` landed blocks.
+    pub fn min_blocks(self, blocks: u64) -> Self {
+        self.filter(move |b| b.blocks >= blocks)
+    }
+
+    /// Keep only builders that run on the given chain.
+    pub fn chain(self, chain_id: u64) -> Self {
+        self.filter(move |b| b.chain_id == chain_id)
+    }
+
+    /// Keep only the builder with the given identifier.
+    pub fn by_identifier(self, identifier: &str) -> Self {
+        let identifier = identifier.to_string();
+        self.filter(move |b| b.identifier == identifier)
+    }
+
+    /// Add an arbitrary predicate to the query.
+    pub fn filter(mut self, predicate: impl Fn(&Builder<'a>) -> bool + 'a) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    /// Collect all matching builders, preserving source order.
+    pub fn collect(self) -> Vec<&'a Builder<'a>> {
+        self.builders.iter().filter(|b| self.matches(b)).collect()
+    }
+
+    /// Return the first matching builder, if any.
+    pub fn first(self) -> Option<&'a Builder<'a>> {
+        self.builders.iter().find(|b| self.matches(b))
+    }
+
+    /// Collect matching builders into a map keyed by identifier.
+    pub fn into_map(self) -> HashMap<&'a str, &'a Builder<'a>> {
+        self.builders.iter().filter(|b| self.matches(b)).map(|b| (b.identifier, b)).collect()
+    }
+
+    fn matches(&self, builder: &Builder<'a>) -> bool {
+        self.predicates.iter().all(|p| p(builder))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_account_mev_share_builders() {
+        let builders = BuilderQuery::from_static().with_mev_share().account_not_required().collect();
+        for builder in &builders {
+            assert!(builder.mev_share_rpc.is_some());
+            assert!(!builder.account_required);
+        }
+    }
+
+    #[test]
+    fn first_by_identifier_matches() {
+        let identifier = crate::BUILDERS[0].identifier;
+        let found = BuilderQuery::from_static().by_identifier(identifier).first();
+        assert_eq!(found.map(|b| b.identifier), Some(identifier));
+    }
+
+    #[test]
+    fn min_blocks_keeps_order() {
+        let builders = BuilderQuery::from_static().min_blocks(0).collect();
+        assert_eq!(builders.len(), crate::BUILDERS.len());
+        for pair in builders.windows(2) {
+            assert!(pair[0].blocks >= pair[1].blocks);
+        }
+    }
+
+    #[test]
+    fn chain_filter_matches_helper() {
+        let from_query = BuilderQuery::from_static().chain(1).collect();
+        let from_helper = crate::builders_for_chain(1);
+        assert_eq!(from_query.len(), from_helper.len());
+        for builder in &from_query {
+            assert_eq!(builder.chain_id, 1);
+        }
+    }
+
+    #[test]
+    fn into_map_keyed_by_identifier() {
+        let map = BuilderQuery::from_static().signing(Signing::Required).into_map();
+        for (identifier, builder) in &map {
+            assert_eq!(*identifier, builder.identifier);
+            assert_eq!(builder.signing, Signing::Required);
+        }
+    }
+}
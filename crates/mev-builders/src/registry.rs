@@ -0,0 +1,323 @@
+//! Runtime-loadable builder registry.
+//!
+//! The [`BUILDERS`](crate::BUILDERS) static is baked in at compile time by the
+//! `include_builders!` macro, so changing the set means recompiling the
+//! downstream crate. The [`Registry`] parses the same JSON schema at runtime
+//! into owned [`Builder`] values and keeps them behind an [`ArcSwap`], so a
+//! long-running bot can pick up new builders or changed RPC endpoints without a
+//! redeploy. With the `watch` feature it also reloads automatically when the
+//! backing `builders.json` changes on disk.
+
+use crate::{Builder, Signing};
+use anyhow::{Context, Result};
+use arc_swap::ArcSwap;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Owned counterpart of [`Builder`].
+///
+/// [`Builder`] borrows its string fields with a lifetime, which suits the
+/// compile-time static but not a set that is parsed and replaced at runtime.
+/// `OwnedBuilder` owns its data and hands out borrowed [`Builder`] views via
+/// [`OwnedBuilder::as_builder`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct OwnedBuilder {
+    /// Human-readable name of the builder.
+    pub name: String,
+    /// Unique identifier for the builder.
+    pub identifier: String,
+    /// Website URL for the builder.
+    pub website: String,
+    /// RPC endpoint for the searcher.
+    pub searcher_rpc: String,
+    /// Optional RPC endpoint for MEV share.
+    #[serde(default)]
+    pub mev_share_rpc: Option<String>,
+    /// The extra data provided by the builder in a block.
+    #[serde(default)]
+    pub extra_data: Option<String>,
+    /// Indicates if the builder requires signing for bundles.
+    pub signing: Signing,
+    /// Requires account to use the RPC.
+    pub account_required: bool,
+    /// Chain the endpoints belong to. Defaults to Ethereum mainnet (1) when omitted.
+    #[serde(default = "default_chain_id")]
+    pub chain_id: u64,
+    /// Number of blocks landed by this builder. Not part of `builders.json`; populated from stats.
+    #[serde(default)]
+    pub blocks: u64,
+}
+
+/// Default chain id (Ethereum mainnet) for builder records that omit `chain_id`.
+fn default_chain_id() -> u64 {
+    1
+}
+
+impl OwnedBuilder {
+    /// Borrow this builder as a [`Builder`] view.
+    pub fn as_builder(&self) -> Builder<'_> {
+        Builder {
+            name: &self.name,
+            identifier: &self.identifier,
+            website: &self.website,
+            searcher_rpc: &self.searcher_rpc,
+            mev_share_rpc: self.mev_share_rpc.as_deref(),
+            extra_data: self.extra_data.as_deref(),
+            signing: self.signing.clone(),
+            account_required: self.account_required,
+            chain_id: self.chain_id,
+            blocks: self.blocks,
+        }
+    }
+}
+
+impl From<&Builder<'_>> for OwnedBuilder {
+    fn from(builder: &Builder<'_>) -> Self {
+        Self {
+            name: builder.name.to_string(),
+            identifier: builder.identifier.to_string(),
+            website: builder.website.to_string(),
+            searcher_rpc: builder.searcher_rpc.to_string(),
+            mev_share_rpc: builder.mev_share_rpc.map(str::to_string),
+            extra_data: builder.extra_data.map(str::to_string),
+            signing: builder.signing.clone(),
+            account_required: builder.account_required,
+            chain_id: builder.chain_id,
+            blocks: builder.blocks,
+        }
+    }
+}
+
+/// A runtime registry of builders backed by an [`ArcSwap`].
+///
+/// Reads ([`current`](Registry::current)) are lock-free; a reload atomically
+/// swaps in the new generation and returns the previous one.
+pub struct Registry {
+    builders: ArcSwap<Vec<OwnedBuilder>>,
+}
+
+impl Registry {
+    /// Create a registry seeded from the compile-time [`BUILDERS`](crate::BUILDERS) static.
+    pub fn from_static(builders: &[Builder<'_>]) -> Self {
+        Self::from_owned(builders.iter().map(OwnedBuilder::from).collect())
+    }
+
+    /// Create a registry from an already owned set of builders.
+    pub fn from_owned(builders: Vec<OwnedBuilder>) -> Self {
+        Self { builders: ArcSwap::from_pointee(sorted(builders)) }
+    }
+
+    /// Load a registry from a `builders.json` file on disk.
+    ///
+    /// Landed-block counts live in `builders_stats.json`, not `builders.json`, so
+    /// builders loaded this way carry `blocks == 0` unless a record sets a
+    /// `blocks` field itself — unlike the compile-time
+    /// [`BUILDERS`](crate::BUILDERS) static, which the macro merges with the stats
+    /// file. Block-ordering and `min_blocks` filtering are therefore inert for
+    /// such sets; use [`load_with_stats_from_path`](Self::load_with_stats_from_path)
+    /// to populate landed-block data.
+    pub fn load_from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        Self::load_from_str(&content)
+    }
+
+    /// Load a registry from any reader yielding `builders.json` content.
+    ///
+    /// Reads landed-block counts only from records that set `blocks`; see [`load_from_path`](Self::load_from_path).
+    pub fn load_from_reader(mut reader: impl Read) -> Result<Self> {
+        let mut content = String::new();
+        reader.read_to_string(&mut content).context("failed to read builders")?;
+        Self::load_from_str(&content)
+    }
+
+    /// Parse a registry from a `builders.json` string.
+    ///
+    /// Reads landed-block counts only from records that set `blocks`; see [`load_from_path`](Self::load_from_path).
+    pub fn load_from_str(content: &str) -> Result<Self> {
+        let builders: Vec<OwnedBuilder> = serde_json::from_str(content).context("failed to parse builders.json")?;
+        Ok(Self::from_owned(builders))
+    }
+
+    /// Load a registry from `builders.json`, merging landed-block counts from a
+    /// `builders_stats.json` file keyed by `extra_data`.
+    ///
+    /// This mirrors the compile-time `include_builders!` merge, so the resulting
+    /// set carries the same block counts (and therefore the same ordering and
+    /// `min_blocks` behavior) as the static [`BUILDERS`](crate::BUILDERS).
+    pub fn load_with_stats_from_path(builders_path: impl AsRef<Path>, stats_path: impl AsRef<Path>) -> Result<Self> {
+        let builders_path = builders_path.as_ref();
+        let builders_content =
+            std::fs::read_to_string(builders_path).with_context(|| format!("failed to read {}", builders_path.display()))?;
+        let mut builders: Vec<OwnedBuilder> = serde_json::from_str(&builders_content).context("failed to parse builders.json")?;
+
+        let stats_path = stats_path.as_ref();
+        let stats_content = std::fs::read_to_string(stats_path).with_context(|| format!("failed to read {}", stats_path.display()))?;
+        let stats: HashMap<String, u64> = serde_json::from_str(&stats_content).context("failed to parse builders_stats.json")?;
+
+        for builder in &mut builders {
+            builder.blocks = builder.extra_data.as_deref().and_then(|extra_data| stats.get(extra_data)).copied().unwrap_or(0);
+        }
+
+        Ok(Self::from_owned(builders))
+    }
+
+    /// Return the current generation of builders. Lock-free.
+    pub fn current(&self) -> Arc<Vec<OwnedBuilder>> {
+        self.builders.load_full()
+    }
+
+    /// Atomically replace the set of builders, returning the previous generation.
+    pub fn swap(&self, builders: Vec<OwnedBuilder>) -> Arc<Vec<OwnedBuilder>> {
+        self.builders.swap(Arc::new(sorted(builders)))
+    }
+
+    /// Reload the set from `builders.json` and atomically swap it in, returning the previous generation.
+    pub fn reload_from_path(&self, path: impl AsRef<Path>) -> Result<Arc<Vec<OwnedBuilder>>> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+        let builders: Vec<OwnedBuilder> = serde_json::from_str(&content).context("failed to parse builders.json")?;
+        Ok(self.swap(builders))
+    }
+}
+
+/// Sort builders by landed blocks (descending), mirroring the static [`BUILDERS`](crate::BUILDERS) order.
+///
+/// The sort is stable, so sets loaded without stats (all `blocks == 0`) keep
+/// their original file order rather than being shuffled.
+fn sorted(mut builders: Vec<OwnedBuilder>) -> Vec<OwnedBuilder> {
+    builders.sort_by(|a, b| b.blocks.cmp(&a.blocks));
+    builders
+}
+
+#[cfg(feature = "watch")]
+mod watch {
+    use super::*;
+    use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
+    use std::path::PathBuf;
+
+    /// Guard returned by [`Registry::watch`]. Dropping it stops the watcher.
+    pub struct WatchGuard {
+        _watcher: RecommendedWatcher,
+    }
+
+    impl Registry {
+        /// Watch `path` and reload the registry whenever the file changes on disk.
+        ///
+        /// The registry is wrapped in an [`Arc`] so the watcher thread can swap it in place.
+        /// `on_reload` is invoked with the previous generation after each successful swap;
+        /// reload errors (e.g. a transient truncated write) are passed to `on_error`.
+        /// The returned [`WatchGuard`] must be kept alive for watching to continue.
+        pub fn watch<R, E>(self: &Arc<Self>, path: impl Into<PathBuf>, mut on_reload: R, mut on_error: E) -> Result<WatchGuard>
+        where
+            R: FnMut(Arc<Vec<OwnedBuilder>>) + Send + 'static,
+            E: FnMut(anyhow::Error) + Send + 'static,
+        {
+            let path = path.into();
+            let registry = Arc::clone(self);
+            let watched = path.clone();
+
+            let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+                Ok(event) if event.kind.is_modify() || event.kind.is_create() => match registry.reload_from_path(&watched) {
+                    Ok(previous) => on_reload(previous),
+                    Err(err) => on_error(err),
+                },
+                Ok(_) => {}
+                Err(err) => on_error(anyhow::Error::new(err)),
+            })
+            .context("failed to create file watcher")?;
+
+            watcher.watch(&path, RecursiveMode::NonRecursive).with_context(|| format!("failed to watch {}", path.display()))?;
+
+            Ok(WatchGuard { _watcher: watcher })
+        }
+    }
+}
+
+#[cfg(feature = "watch")]
+pub use watch::WatchGuard;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BUILDERS;
+
+    const SAMPLE: &str = r#"[
+        {
+            "name": "Test One",
+            "identifier": "testone",
+            "website": "https://one.example",
+            "searcher_rpc": "https://rpc.one.example",
+            "mev_share_rpc": null,
+            "extra_data": "one",
+            "signing": "Optional",
+            "account_required": false
+        },
+        {
+            "name": "Test Two",
+            "identifier": "testtwo",
+            "website": "https://two.example",
+            "searcher_rpc": "https://rpc.two.example",
+            "extra_data": "two",
+            "signing": "Required",
+            "account_required": true,
+            "blocks": 42
+        }
+    ]"#;
+
+    #[test]
+    fn seeds_from_static() {
+        let registry = Registry::from_static(BUILDERS);
+        let current = registry.current();
+        assert_eq!(current.len(), BUILDERS.len());
+        assert_eq!(current[0].as_builder(), BUILDERS[0]);
+    }
+
+    #[test]
+    fn loads_from_str_and_sorts_by_blocks() {
+        let registry = Registry::load_from_str(SAMPLE).unwrap();
+        let current = registry.current();
+        assert_eq!(current.len(), 2);
+        // Sorted descending by blocks, so the builder with 42 blocks comes first.
+        assert_eq!(current[0].identifier, "testtwo");
+        assert_eq!(current[0].blocks, 42);
+    }
+
+    #[test]
+    fn loads_bundled_builders_json() {
+        // The runtime loader must accept the exact same file the compile-time
+        // macro bakes in, including however `builders.json` spells the signing field.
+        let builders = concat!(env!("CARGO_MANIFEST_DIR"), "/data/builders.json");
+        let stats = concat!(env!("CARGO_MANIFEST_DIR"), "/data/builders_stats.json");
+
+        let registry = Registry::load_from_path(builders).unwrap();
+        assert_eq!(registry.current().len(), BUILDERS.len());
+
+        // Merging stats reproduces the static ordering and block counts.
+        let with_stats = Registry::load_with_stats_from_path(builders, stats).unwrap();
+        let current = with_stats.current();
+        assert_eq!(current.len(), BUILDERS.len());
+        for (owned, expected) in current.iter().zip(BUILDERS) {
+            assert_eq!(&owned.as_builder(), expected);
+        }
+    }
+
+    #[test]
+    fn signing_deserializes_leniently() {
+        // Anything that is not "Required"/"Optional" maps to NotSupported, matching the macro.
+        assert_eq!(serde_json::from_str::<Signing>(r#""Required""#).unwrap(), Signing::Required);
+        assert_eq!(serde_json::from_str::<Signing>(r#""Optional""#).unwrap(), Signing::Optional);
+        assert_eq!(serde_json::from_str::<Signing>(r#""None""#).unwrap(), Signing::NotSupported);
+    }
+
+    #[test]
+    fn swap_returns_previous_generation() {
+        let registry = Registry::load_from_str(SAMPLE).unwrap();
+        let previous = registry.swap(Vec::new());
+        assert_eq!(previous.len(), 2);
+        assert!(registry.current().is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+//! Bundle signing for the `X-Flashbots-Signature` header.
+//!
+//! The [`Signing`](crate::Signing) enum only records *whether* a builder wants
+//! signed bundles; this module actually produces the header value. A
+//! [`BundleSigner`] wraps a secp256k1 private key and signs a JSON-RPC request
+//! body, and [`verify`] recovers the signer address from an inbound header so
+//! builders and relays can authenticate the sender.
+//!
+//! The scheme mirrors what Flashbots-compatible builders expect: the raw UTF-8
+//! request body is hashed with `keccak256`, the 32-byte digest is hex-encoded as
+//! a `0x`-prefixed string, and *that string* is signed as an EIP-191 personal
+//! message with recoverable ECDSA (a 65-byte `r‖s‖v` signature).
+
+use crate::Builder;
+use alloy_primitives::{hex, keccak256, Address, B256};
+use anyhow::{Context, Result};
+use k256::ecdsa::{RecoveryId, Signature, SigningKey, VerifyingKey};
+
+/// Signs JSON-RPC request bodies for the `X-Flashbots-Signature` header.
+///
+/// The key material lives here rather than on [`Builder`] so the builder set
+/// stays pure metadata.
+pub struct BundleSigner {
+    key: SigningKey,
+    address: Address,
+}
+
+impl BundleSigner {
+    /// Create a signer from a secp256k1 signing key.
+    pub fn new(key: SigningKey) -> Self {
+        let address = verifying_key_to_address(key.verifying_key());
+        Self { key, address }
+    }
+
+    /// Create a signer from 32 raw private-key bytes.
+    pub fn from_slice(bytes: &[u8]) -> Result<Self> {
+        let key = SigningKey::from_slice(bytes).context("invalid secp256k1 private key")?;
+        Ok(Self::new(key))
+    }
+
+    /// The signer address derived from the public key.
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Produce the `X-Flashbots-Signature` header value for `body`.
+    ///
+    /// The value is `"{address}:0x{signature_hex}"` where the address is the
+    /// EIP-55 checksummed `0x` form of the signer.
+    pub fn sign(&self, body: &str) -> Result<String> {
+        let hash = flashbots_message_hash(body.as_bytes());
+        let (signature, recid) = self.key.sign_prehash_recoverable(hash.as_slice()).context("failed to sign bundle")?;
+        Ok(format!("{}:0x{}", self.address, hex::encode(encode_signature(&signature, recid))))
+    }
+}
+
+/// Produce the `X-Flashbots-Signature` header value for `body` using `key`.
+///
+/// Convenience wrapper for callers that hold a bare [`SigningKey`] rather than a [`BundleSigner`].
+pub fn sign(key: &SigningKey, body: &str) -> Result<String> {
+    BundleSigner::new(key.clone()).sign(body)
+}
+
+/// Recover the signer address from an `X-Flashbots-Signature` header. Alias for [`verify`].
+pub fn recover(header: &str, body: &str) -> Result<Address> {
+    verify(header, body)
+}
+
+impl Builder<'_> {
+    /// Sign `body` honoring this builder's signing policy.
+    ///
+    /// Returns `None` when the builder does not support signing, otherwise the
+    /// `X-Flashbots-Signature` header value.
+    pub fn sign_payload(&self, signer: &BundleSigner, body: &str) -> Option<Result<String>> {
+        if self.signing.is_not_supported() {
+            return None;
+        }
+        Some(signer.sign(body))
+    }
+}
+
+/// Recover the signer address from an `X-Flashbots-Signature` header and its body.
+///
+/// Returns an error if the header is malformed or the address it claims does
+/// not match the recovered one.
+pub fn verify(header: &str, body: &str) -> Result<Address> {
+    let (claimed, sig) = header.split_once(':').context("header must be 'address:signature'")?;
+    let claimed: Address = claimed.parse().context("invalid signer address in header")?;
+
+    let sig_bytes = hex::decode(sig).context("invalid signature hex")?;
+    anyhow::ensure!(sig_bytes.len() == 65, "signature must be 65 bytes, got {}", sig_bytes.len());
+    let recid = RecoveryId::from_byte(sig_bytes[64].checked_sub(27).context("invalid recovery id")?).context("invalid recovery id")?;
+    let signature = Signature::from_slice(&sig_bytes[..64]).context("invalid signature")?;
+
+    let hash = flashbots_message_hash(body.as_bytes());
+    let recovered = VerifyingKey::recover_from_prehash(hash.as_slice(), &signature, recid).context("failed to recover signer")?;
+    let address = verifying_key_to_address(&recovered);
+    anyhow::ensure!(address == claimed, "recovered address does not match header");
+    Ok(address)
+}
+
+/// EIP-191 hash of the hex-encoded `keccak256(body)` digest.
+fn flashbots_message_hash(body: &[u8]) -> B256 {
+    let digest = format!("0x{}", hex::encode(keccak256(body)));
+    eip191_hash(digest.as_bytes())
+}
+
+/// `keccak256("\x19Ethereum Signed Message:\n" + len + message)`.
+fn eip191_hash(message: &[u8]) -> B256 {
+    let mut buf = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    buf.extend_from_slice(message);
+    keccak256(buf)
+}
+
+/// Encode a recoverable signature as 65 bytes `r‖s‖v` with `v ∈ {27, 28}`.
+fn encode_signature(signature: &Signature, recid: RecoveryId) -> [u8; 65] {
+    let mut out = [0u8; 65];
+    out[..64].copy_from_slice(&signature.to_bytes());
+    out[64] = recid.to_byte() + 27;
+    out
+}
+
+/// Derive the Ethereum address from a secp256k1 public key.
+fn verifying_key_to_address(key: &VerifyingKey) -> Address {
+    let encoded = key.to_encoded_point(false);
+    Address::from_slice(&keccak256(&encoded.as_bytes()[1..])[12..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Deterministic test key (never use a hardcoded key in production).
+    const PRIVATE_KEY: [u8; 32] = [0x11; 32];
+
+    #[test]
+    fn sign_and_verify_round_trip() {
+        let signer = BundleSigner::from_slice(&PRIVATE_KEY).unwrap();
+        let body = r#"{"jsonrpc":"2.0","method":"eth_sendBundle","params":[],"id":1}"#;
+
+        let header = signer.sign(body).unwrap();
+        assert!(header.starts_with(&format!("{}:0x", signer.address())));
+
+        let recovered = verify(&header, body).unwrap();
+        assert_eq!(recovered, signer.address());
+    }
+
+    #[test]
+    fn free_functions_match_signer() {
+        let key = SigningKey::from_slice(&PRIVATE_KEY).unwrap();
+        let body = "body";
+        let header = sign(&key, body).unwrap();
+        assert_eq!(header, BundleSigner::new(key).sign(body).unwrap());
+        assert!(recover(&header, body).is_ok());
+    }
+
+    #[test]
+    fn verify_rejects_tampered_body() {
+        let signer = BundleSigner::from_slice(&PRIVATE_KEY).unwrap();
+        let header = signer.sign("original").unwrap();
+        assert!(verify(&header, "tampered").is_err());
+    }
+
+    #[test]
+    fn sign_payload_respects_policy() {
+        let signer = BundleSigner::from_slice(&PRIVATE_KEY).unwrap();
+
+        let mut builder = crate::BUILDERS[0].clone();
+        builder.signing = crate::Signing::NotSupported;
+        assert!(builder.sign_payload(&signer, "body").is_none());
+
+        builder.signing = crate::Signing::Required;
+        assert!(builder.sign_payload(&signer, "body").unwrap().is_ok());
+    }
+}
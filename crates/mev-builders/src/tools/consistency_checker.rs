@@ -14,6 +14,12 @@ struct BuilderJson {
     extra_data: Option<String>,
     signing: String,
     account_required: bool,
+    #[serde(default = "default_chain_id")]
+    chain_id: u64,
+}
+
+fn default_chain_id() -> u64 {
+    1
 }
 
 #[derive(Debug)]
@@ -0,0 +1,2 @@
+pub mod consistency_checker;
+pub mod stats_aggregator;
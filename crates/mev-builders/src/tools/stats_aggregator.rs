@@ -4,29 +4,86 @@ use reqwest::blocking::Client;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Deserialize)]
-struct RelayResponse {
-    builders: Vec<RelayBuilder>,
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RelayResponse {
+    pub builders: Vec<RelayBuilder>,
 }
 
-#[derive(Debug, Deserialize)]
-struct RelayBuilder {
-    info: BuilderInfo,
-    children: Option<Vec<ChildBuilder>>,
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RelayBuilder {
+    pub info: BuilderInfo,
+    pub children: Option<Vec<ChildBuilder>>,
 }
 
-#[derive(Debug, Deserialize)]
-struct BuilderInfo {
-    extra_data: String,
-    num_blocks: u64,
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BuilderInfo {
+    pub extra_data: String,
+    pub num_blocks: u64,
 }
 
-#[derive(Debug, Deserialize)]
-struct ChildBuilder {
-    extra_data: String,
-    num_blocks: u64,
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ChildBuilder {
+    pub extra_data: String,
+    pub num_blocks: u64,
+}
+
+/// A source of daily relay/builder block counts.
+///
+/// The relayscan.io endpoint is one implementation ([`RelayscanSource`]); users
+/// can add their own relay or block-explorer source without touching the
+/// aggregation logic.
+pub trait StatsSource {
+    /// Stable identifier for this source, used in cache keys.
+    fn name(&self) -> &str;
+
+    /// Fetch relay data for a single day (`YYYY-MM-DD`).
+    fn fetch_day(&self, date: &str) -> Result<RelayResponse>;
+}
+
+/// Default relayscan base URL for Ethereum mainnet stats.
+const DEFAULT_RELAY_BASE_URL: &str = "https://www.relayscan.io";
+
+/// [`StatsSource`] backed by relayscan.io's `stats/day/{date}/json` endpoint.
+pub struct RelayscanSource {
+    client: Client,
+    base_url: String,
+}
+
+impl RelayscanSource {
+    /// Create a relayscan source pointing at the default mainnet base URL.
+    pub fn new() -> Result<Self> {
+        Self::with_base_url(DEFAULT_RELAY_BASE_URL)
+    }
+
+    /// Create a relayscan source pointing at a custom base URL.
+    ///
+    /// Use this to regenerate stats for a network other than Ethereum mainnet
+    /// rather than relying on the hardcoded mainnet path.
+    pub fn with_base_url(base_url: impl Into<String>) -> Result<Self> {
+        let client = Client::builder().timeout(std::time::Duration::from_secs(10)).build().context("Failed to create HTTP client")?;
+
+        Ok(Self { client, base_url: base_url.into() })
+    }
+}
+
+impl StatsSource for RelayscanSource {
+    fn name(&self) -> &str {
+        "relayscan"
+    }
+
+    fn fetch_day(&self, date: &str) -> Result<RelayResponse> {
+        let url = format!("{}/stats/day/{}/json", self.base_url.trim_end_matches('/'), date);
+
+        let response = self.client.get(&url).send().context(format!("Failed to fetch data for {}", date))?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("HTTP error: {}", response.status());
+        }
+
+        response.json().context("Failed to parse JSON response")
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -43,34 +100,62 @@ pub struct HierarchicalChild {
 }
 
 pub struct StatsAggregator {
-    client: Client,
+    sources: Vec<Box<dyn StatsSource>>,
+    cache_dir: Option<PathBuf>,
 }
 
 impl StatsAggregator {
+    /// Create an aggregator with the default relayscan source and no cache.
     pub fn new() -> Result<Self> {
-        let client = Client::builder()
-            .timeout(std::time::Duration::from_secs(10))
-            .build()
-            .context("Failed to create HTTP client")?;
-        
-        Ok(Self { client })
+        Ok(Self { sources: vec![Box::new(RelayscanSource::new()?)], cache_dir: None })
     }
 
-    /// Fetch relay data for a specific date
-    pub fn fetch_relay_data(&self, date: &str) -> Result<RelayResponse> {
-        let url = format!("https://www.relayscan.io/stats/day/{}/json", date);
-        
-        let response = self.client
-            .get(&url)
-            .send()
-            .context(format!("Failed to fetch data for {}", date))?;
-        
-        if !response.status().is_success() {
-            anyhow::bail!("HTTP error: {}", response.status());
+    /// Create an aggregator from an explicit set of sources.
+    ///
+    /// The per-`extra_data` block counts from every source are summed together
+    /// in [`aggregate_and_save`](Self::aggregate_and_save), so multiple relays
+    /// or explorers can be cross-checked and merged.
+    pub fn from_sources(sources: Vec<Box<dyn StatsSource>>) -> Self {
+        Self { sources, cache_dir: None }
+    }
+
+    /// Register an additional source whose counts are merged into the aggregate.
+    pub fn with_source(mut self, source: Box<dyn StatsSource>) -> Self {
+        self.sources.push(source);
+        self
+    }
+
+    /// Enable a filesystem-backed cache keyed by `(source, date)`.
+    ///
+    /// Days already fetched into `cache_dir` are read from disk instead of being
+    /// re-downloaded, making regeneration reproducible and offline-friendly.
+    pub fn with_cache_dir(mut self, cache_dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(cache_dir.into());
+        self
+    }
+
+    /// Fetch a day from `source`, consulting and populating the cache when enabled.
+    fn fetch_day_cached(&self, source: &dyn StatsSource, date: &str) -> Result<RelayResponse> {
+        let cache_path = self.cache_dir.as_ref().map(|dir| dir.join(format!("{}-{}.json", source.name(), date)));
+
+        if let Some(path) = &cache_path {
+            if path.exists() {
+                let content = fs::read_to_string(path).with_context(|| format!("Failed to read cache file: {}", path.display()))?;
+                return serde_json::from_str(&content).with_context(|| format!("Failed to parse cache file: {}", path.display()));
+            }
         }
-        
-        response.json()
-            .context("Failed to parse JSON response")
+
+        let response = source.fetch_day(date)?;
+
+        if let Some(path) = &cache_path {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).with_context(|| format!("Failed to create cache directory: {}", parent.display()))?;
+            }
+            let json = serde_json::to_string_pretty(&response).context("Failed to serialize cache entry")?;
+            fs::write(path, json).with_context(|| format!("Failed to write cache file: {}", path.display()))?;
+        }
+
+        Ok(response)
     }
 
     /// Aggregate builders from relay data
@@ -222,21 +307,24 @@ impl StatsAggregator {
 
         for date_str in &dates {
             println!("Fetching data for {}...", date_str);
-            
-            match self.fetch_relay_data(date_str) {
-                Ok(data) => {
-                    let (hierarchical, flat_aggregated) = self.aggregate_builders(data.builders);
-                    
-                    // Add to total flat aggregation
-                    for (extra_data, num_blocks) in &flat_aggregated {
-                        *total_flat_aggregated.entry(extra_data.clone()).or_insert(0) += num_blocks;
+
+            // Fetch the day from every source and merge their counts together.
+            for source in &self.sources {
+                match self.fetch_day_cached(source.as_ref(), date_str) {
+                    Ok(data) => {
+                        let (hierarchical, flat_aggregated) = self.aggregate_builders(data.builders);
+
+                        // Add to total flat aggregation
+                        for (extra_data, num_blocks) in &flat_aggregated {
+                            *total_flat_aggregated.entry(extra_data.clone()).or_insert(0) += num_blocks;
+                        }
+
+                        println!("  [{}] Found {} unique parent builders", source.name(), hierarchical.len());
+                        all_hierarchical_data.push(hierarchical);
+                    }
+                    Err(e) => {
+                        println!("  [{}] Error: {}", source.name(), e);
                     }
-                    
-                    println!("  Found {} unique parent builders", hierarchical.len());
-                    all_hierarchical_data.push(hierarchical);
-                }
-                Err(e) => {
-                    println!("  Error: {}", e);
                 }
             }
         }